@@ -1,3 +1,25 @@
+use crate::converters::ReportConverter;
+
+/// [`ReportConverter`] for CQG fill reports.
+pub struct CqgFillReport;
+
+impl ReportConverter for CqgFillReport {
+    fn name(&self) -> &'static str {
+        "CQGFillReport"
+    }
+
+    fn detect(&self, rows: &[Vec<String>]) -> bool {
+        rows.first()
+            .and_then(|row| row.first())
+            .map(|cell| cell.contains("Fills reported as of"))
+            .unwrap_or(false)
+    }
+
+    fn convert(&self, rows: Vec<Vec<String>>) -> Result<Vec<Vec<String>>, Box<dyn std::error::Error>> {
+        convert(rows)
+    }
+}
+
 /// Convert CQG Fill Report to TraderVue CSV format
 ///
 /// Expected output columns: Date, Time, Symbol, Quantity, Price, Side
@@ -95,6 +117,12 @@ struct HeaderIndices {
     fill_price_idx: usize,
 }
 
+/// Scans `rows` for the first row that looks like a CQG fill-report header,
+/// used by `converters::metadata` to report the detected header row.
+pub(crate) fn find_header_row(rows: &[Vec<String>]) -> Option<usize> {
+    rows.iter().position(|row| parse_header_row(row).is_ok())
+}
+
 fn parse_header_row(header_row: &[String]) -> Result<HeaderIndices, Box<dyn std::error::Error>> {
     let mut time_idx = None;
     let mut symbol_idx = None;
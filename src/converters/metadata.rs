@@ -0,0 +1,64 @@
+use std::path::Path;
+
+use calamine::{Reader, Xlsx, open_workbook};
+use serde::Serialize;
+
+use crate::converters::{cqg_fill_report, range_to_rows};
+
+/// Per-worksheet summary used by `tradervue-conv metadata` to debug why a
+/// conversion picked the wrong sheet or mis-detected columns.
+#[derive(Serialize)]
+pub struct SheetMetadata {
+    pub name: String,
+    pub rows: usize,
+    pub columns: usize,
+    pub header_row: Option<usize>,
+}
+
+/// Opens the workbook at `path` once and collects metadata for every
+/// worksheet without running any report conversion. Reuses `range_to_rows`
+/// (the same cell-conversion logic `parse_xlsx` uses, e.g. for
+/// `Data::DateTime`) on each worksheet's already-loaded `Range`, rather than
+/// reopening and reparsing the file from disk per sheet.
+pub fn inspect(path: &Path) -> Result<Vec<SheetMetadata>, Box<dyn std::error::Error>> {
+    let mut workbook: Xlsx<_> = open_workbook(path)?;
+    let sheet_names = workbook.sheet_names().to_vec();
+
+    let mut sheets = Vec::with_capacity(sheet_names.len());
+    for name in sheet_names {
+        let range = workbook.worksheet_range(&name)?;
+        let rows = range_to_rows(&range);
+
+        let columns = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        let header_row = cqg_fill_report::find_header_row(&rows);
+
+        sheets.push(SheetMetadata {
+            name,
+            rows: rows.len(),
+            columns,
+            header_row,
+        });
+    }
+
+    Ok(sheets)
+}
+
+/// Renders metadata as pretty-printed JSON.
+pub fn to_json(sheets: &[SheetMetadata]) -> Result<String, Box<dyn std::error::Error>> {
+    Ok(serde_json::to_string_pretty(sheets)?)
+}
+
+/// Renders metadata as a CSV table (Sheet, Rows, Columns, HeaderRow).
+pub fn to_csv(sheets: &[SheetMetadata]) -> Result<String, Box<dyn std::error::Error>> {
+    let mut writer = csv::WriterBuilder::new().from_writer(Vec::new());
+    writer.write_record(["Sheet", "Rows", "Columns", "HeaderRow"])?;
+    for sheet in sheets {
+        writer.write_record(&[
+            sheet.name.clone(),
+            sheet.rows.to_string(),
+            sheet.columns.to_string(),
+            sheet.header_row.map(|r| r.to_string()).unwrap_or_default(),
+        ])?;
+    }
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
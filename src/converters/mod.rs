@@ -1,8 +1,47 @@
 pub mod cqg_fill_report;
+pub mod metadata;
+#[cfg(feature = "pdf")]
+pub mod pdf;
 
 use std::path::Path;
-use calamine::{Data, ExcelDateTime, Reader, Xlsx, open_workbook};
-use chrono::NaiveDateTime;
+use calamine::{Data, ExcelDateTime, Range, Reader, Xlsx, open_workbook};
+use chrono::{NaiveDateTime, NaiveTime};
+
+/// A converter from a broker's raw fill-report rows to TraderVue CSV rows.
+///
+/// New broker formats should live in their own module next to
+/// [`cqg_fill_report`] and register an instance in [`registry`], rather than
+/// being wired into `main.rs` directly.
+pub trait ReportConverter {
+    /// Human-readable name used in logs and `--type auto` diagnostics.
+    fn name(&self) -> &'static str;
+
+    /// Returns `true` if `rows` looks like this converter's report format.
+    fn detect(&self, rows: &[Vec<String>]) -> bool;
+
+    /// Converts the raw rows into TraderVue-ready CSV rows.
+    fn convert(&self, rows: Vec<Vec<String>>) -> Result<Vec<Vec<String>>, Box<dyn std::error::Error>>;
+}
+
+/// All known broker report converters, in detection priority order.
+pub fn registry() -> Vec<Box<dyn ReportConverter>> {
+    vec![Box::new(cqg_fill_report::CqgFillReport)]
+}
+
+/// Runs `rows` through each registered converter's `detect` and converts
+/// using the first match.
+pub fn convert_auto(rows: Vec<Vec<String>>) -> Result<Vec<Vec<String>>, Box<dyn std::error::Error>> {
+    let candidates = registry();
+    let converter = candidates.iter().find(|c| c.detect(&rows)).ok_or_else(|| {
+        let tried: Vec<&str> = candidates.iter().map(|c| c.name()).collect();
+        format!(
+            "Could not detect report type from input (tried: {}); specify --type explicitly",
+            tried.join(", ")
+        )
+    })?;
+
+    converter.convert(rows)
+}
 
 /// Convert Excel time value (fraction of day) to HH:MM:SS format
 fn excel_datetime_to_string(dt: &ExcelDateTime) -> String {
@@ -10,43 +49,305 @@ fn excel_datetime_to_string(dt: &ExcelDateTime) -> String {
     naive_dt.format("%H:%M:%S").to_string()
 }
 
-/// Parse XLSX file into Vec<Vec<String>>
-pub fn parse_xlsx(path: &Path) -> Result<Vec<Vec<String>>, Box<dyn std::error::Error>> {
-    let mut workbook: Xlsx<_> = open_workbook(path)?;
-
-    // Get the first worksheet
-    let sheet_name = workbook
-        .sheet_names()
-        .first()
-        .ok_or("No worksheets found")?
-        .clone();
-
-    let range = workbook
-        .worksheet_range(&sheet_name)?;
-
-    // Convert to Vec<Vec<String>>
-    let rows = range
+/// Converts a worksheet range into `Vec<Vec<String>>`, rendering
+/// `Data::DateTime` cells as `HH:MM:SS` like [`parse_xlsx`] does. Shared so
+/// callers that already have an open workbook (e.g. `metadata::inspect`)
+/// don't need to reopen the file through `parse_xlsx` just to get this
+/// conversion.
+pub(crate) fn range_to_rows(range: &Range<Data>) -> Vec<Vec<String>> {
+    range
         .rows()
         .map(|row| {
             row.iter()
-                .map(|cell| {
-                    match cell {
-                        Data::DateTime(dt) => {
-                            excel_datetime_to_string(dt)
-                        }
-                        _ => cell.to_string()
-                    }
+                .map(|cell| match cell {
+                    Data::DateTime(dt) => excel_datetime_to_string(dt),
+                    _ => cell.to_string(),
                 })
                 .collect()
         })
-        .collect();
+        .collect()
+}
+
+/// Selects a worksheet by name or position when a workbook has more than one.
+#[derive(Clone, Debug)]
+pub enum SheetSelector {
+    /// Case-insensitive worksheet name.
+    Name(String),
+    /// Zero-based position; negative counts from the end (`-1` = last sheet).
+    Index(i32),
+}
+
+impl std::str::FromStr for SheetSelector {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<i32>() {
+            Ok(index) => Ok(SheetSelector::Index(index)),
+            Err(_) => Ok(SheetSelector::Name(s.to_string())),
+        }
+    }
+}
+
+impl SheetSelector {
+    /// Resolves this selector against the workbook's sheet names.
+    fn resolve(&self, sheet_names: &[String]) -> Result<String, Box<dyn std::error::Error>> {
+        match self {
+            SheetSelector::Name(name) => sheet_names
+                .iter()
+                .find(|s| s.eq_ignore_ascii_case(name))
+                .cloned()
+                .ok_or_else(|| format!("No worksheet named '{}'", name).into()),
+            SheetSelector::Index(index) => {
+                let len = sheet_names.len() as i32;
+                let resolved = if *index < 0 { len + index } else { *index };
+                if resolved < 0 || resolved >= len {
+                    return Err(format!(
+                        "Sheet index {} out of range (workbook has {} sheets)",
+                        index,
+                        sheet_names.len()
+                    )
+                    .into());
+                }
+                Ok(sheet_names[resolved as usize].clone())
+            }
+        }
+    }
+}
+
+/// A rectangular A1-style cell range, e.g. `C3:T25`.
+#[derive(Clone, Debug)]
+pub struct CellRange {
+    start_row: u32,
+    start_col: u32,
+    end_row: u32,
+    end_col: u32,
+}
+
+impl std::str::FromStr for CellRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid range '{}': expected format like C3:T25", s))?;
+
+        let (start_row, start_col) = parse_cell_ref(start)?;
+        let (end_row, end_col) = parse_cell_ref(end)?;
+
+        Ok(CellRange {
+            start_row: start_row.min(end_row),
+            start_col: start_col.min(end_col),
+            end_row: start_row.max(end_row),
+            end_col: start_col.max(end_col),
+        })
+    }
+}
+
+/// Parse an A1-style cell reference (e.g. `C3`) into 0-based (row, col).
+fn parse_cell_ref(cell: &str) -> Result<(u32, u32), String> {
+    let split_at = cell
+        .find(|c: char| c.is_ascii_digit())
+        .ok_or_else(|| format!("Invalid cell reference '{}'", cell))?;
+    let (col_str, row_str) = cell.split_at(split_at);
+
+    if col_str.is_empty() || row_str.is_empty() {
+        return Err(format!("Invalid cell reference '{}'", cell));
+    }
+
+    let mut col: u32 = 0;
+    for c in col_str.chars() {
+        if !c.is_ascii_alphabetic() {
+            return Err(format!("Invalid cell reference '{}'", cell));
+        }
+        col = col * 26 + (c.to_ascii_uppercase() as u32 - 'A' as u32 + 1);
+    }
+    let col = col - 1;
+
+    let row: u32 = row_str
+        .parse()
+        .map_err(|_| format!("Invalid cell reference '{}'", cell))?;
+    if row == 0 {
+        return Err(format!("Invalid cell reference '{}': row must be >= 1", cell));
+    }
+
+    Ok((row - 1, col))
+}
+
+/// Parse XLSX file into Vec<Vec<String>>, optionally selecting a specific
+/// worksheet by name or index (see [`SheetSelector`]) and restricting
+/// extraction to a rectangular region (see [`CellRange`]); defaults to the
+/// first worksheet and the whole used range. `range` is interpreted as
+/// absolute A1 coordinates, translated against the worksheet's used-range
+/// origin and clamped to the used range; any part of it outside the used
+/// range is dropped.
+pub fn parse_xlsx(
+    path: &Path,
+    sheet: Option<&SheetSelector>,
+    range: Option<&CellRange>,
+) -> Result<Vec<Vec<String>>, Box<dyn std::error::Error>> {
+    let mut workbook: Xlsx<_> = open_workbook(path)?;
+
+    let sheet_names = workbook.sheet_names().to_vec();
+    let sheet_name = match sheet {
+        Some(selector) => selector.resolve(&sheet_names)?,
+        None => sheet_names.first().ok_or("No worksheets found")?.clone(),
+    };
+
+    let worksheet_range = workbook
+        .worksheet_range(&sheet_name)?;
+
+    // `rows()` yields rows relative to the used range's own top-left cell,
+    // which is not necessarily A1 (e.g. a sheet with leading blank rows or
+    // columns), so `--range` must be translated by this origin below.
+    let (origin_row, origin_col) = worksheet_range.start().unwrap_or((0, 0));
+
+    let mut rows = range_to_rows(&worksheet_range);
+
+    if let Some(range) = range {
+        // Clamp to the used range: a start before the origin begins at the
+        // origin, and an end before the origin selects nothing.
+        let start_row = range.start_row.saturating_sub(origin_row);
+        let start_col = range.start_col.saturating_sub(origin_col);
+        let end_row = range.end_row.checked_sub(origin_row).map_or(0, |r| r + 1);
+        let end_col = range.end_col.checked_sub(origin_col).map_or(0, |c| c + 1);
+
+        let row_count = rows.len() as u32;
+        let start_row = start_row.min(row_count);
+        let end_row = end_row.min(row_count);
+
+        rows = rows.drain(start_row as usize..end_row as usize).collect();
+
+        for row in &mut rows {
+            let col_count = row.len() as u32;
+            let start_col = start_col.min(col_count);
+            let end_col = end_col.min(col_count);
+            *row = row.drain(start_col as usize..end_col as usize).collect();
+        }
+    }
 
     Ok(rows)
 }
 
-/// Write Vec<Vec<String>> to CSV file
-pub fn write_csv(path: &Path, rows: Vec<Vec<String>>) -> Result<(), Box<dyn std::error::Error>> {
-    let mut writer = csv::Writer::from_path(path)?;
+/// Filters converted rows (with a `Date/Time/Symbol/...` header, as produced
+/// by [`cqg_fill_report::convert`]) down to a time window and/or symbol set.
+///
+/// `symbols` matches against the `Symbol` column exactly; an empty slice
+/// matches every symbol. `from`/`to` accept `HH:MM:SS` or a full timestamp
+/// (only the time-of-day portion is compared) and are inclusive bounds.
+pub fn filter(
+    rows: Vec<Vec<String>>,
+    symbols: &[String],
+    from: Option<&str>,
+    to: Option<&str>,
+) -> Result<Vec<Vec<String>>, Box<dyn std::error::Error>> {
+    if symbols.is_empty() && from.is_none() && to.is_none() {
+        return Ok(rows);
+    }
+
+    let Some(header) = rows.first().cloned() else {
+        return Ok(rows);
+    };
+
+    let symbol_idx = if !symbols.is_empty() {
+        Some(
+            header
+                .iter()
+                .position(|c| c == "Symbol")
+                .ok_or("Could not find 'Symbol' column")?,
+        )
+    } else {
+        None
+    };
+
+    let from_time = from.map(parse_time_filter).transpose()?;
+    let to_time = to.map(parse_time_filter).transpose()?;
+    let time_idx = if from_time.is_some() || to_time.is_some() {
+        Some(
+            header
+                .iter()
+                .position(|c| c == "Time")
+                .ok_or("Could not find 'Time' column")?,
+        )
+    } else {
+        None
+    };
+
+    let mut result = vec![header];
+    for row in rows.into_iter().skip(1) {
+        if let Some(symbol_idx) = symbol_idx {
+            let symbol = row.get(symbol_idx).map(|s| s.as_str()).unwrap_or("");
+            if !symbols.iter().any(|s| s == symbol) {
+                continue;
+            }
+        }
+
+        if let Some(time_idx) = time_idx {
+            let time = parse_time_filter(row.get(time_idx).map(|s| s.as_str()).unwrap_or(""))?;
+            if from_time.is_some_and(|from| time < from) {
+                continue;
+            }
+            if to_time.is_some_and(|to| time > to) {
+                continue;
+            }
+        }
+
+        result.push(row);
+    }
+
+    Ok(result)
+}
+
+/// Parses a `--from`/`--to` filter bound, accepting `HH:MM:SS` or a full
+/// timestamp (in which case only the time-of-day is used).
+fn parse_time_filter(s: &str) -> Result<NaiveTime, Box<dyn std::error::Error>> {
+    let s = s.trim();
+
+    if let Ok(time) = NaiveTime::parse_from_str(s, "%H:%M:%S") {
+        return Ok(time);
+    }
+
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%m/%d/%Y %H:%M:%S"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(s, format) {
+            return Ok(dt.time());
+        }
+    }
+
+    Err(format!("Could not parse time filter '{}'", s).into())
+}
+
+/// An output field delimiter, accepted as `,`, `;`, or `\t`.
+#[derive(Clone, Copy, Debug)]
+pub struct Delimiter(pub u8);
+
+impl std::str::FromStr for Delimiter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "," => Ok(Delimiter(b',')),
+            ";" => Ok(Delimiter(b';')),
+            "\\t" | "\t" => Ok(Delimiter(b'\t')),
+            _ => Err(format!("Unsupported delimiter '{}': expected ',', ';', or '\\t'", s)),
+        }
+    }
+}
+
+impl Default for Delimiter {
+    fn default() -> Self {
+        Delimiter(b',')
+    }
+}
+
+/// Write Vec<Vec<String>> as CSV (or TSV, depending on `delimiter`) to any
+/// writer, so callers can target a file or a locked stdout handle alike.
+pub fn write_csv<W: std::io::Write>(
+    writer: W,
+    rows: Vec<Vec<String>>,
+    delimiter: Delimiter,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(delimiter.0)
+        .from_writer(writer);
 
     for row in rows {
         writer.write_record(&row)?;
@@ -55,3 +356,68 @@ pub fn write_csv(path: &Path, rows: Vec<Vec<String>>) -> Result<(), Box<dyn std:
     writer.flush()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn cell_range_parses_and_normalizes_a1_bounds() {
+        let range = CellRange::from_str("C3:T25").unwrap();
+        assert_eq!(range.start_row, 2);
+        assert_eq!(range.start_col, 2);
+        assert_eq!(range.end_row, 24);
+        assert_eq!(range.end_col, 19);
+    }
+
+    #[test]
+    fn cell_range_accepts_corners_in_either_order() {
+        let range = CellRange::from_str("T25:C3").unwrap();
+        assert_eq!(range.start_row, 2);
+        assert_eq!(range.start_col, 2);
+        assert_eq!(range.end_row, 24);
+        assert_eq!(range.end_col, 19);
+    }
+
+    #[test]
+    fn cell_range_rejects_missing_separator() {
+        assert!(CellRange::from_str("C3").is_err());
+    }
+
+    #[test]
+    fn cell_range_rejects_row_zero() {
+        assert!(CellRange::from_str("A0:B1").is_err());
+    }
+
+    #[test]
+    fn sheet_selector_resolves_name_case_insensitively() {
+        let sheets = vec!["Fills".to_string(), "Summary".to_string()];
+        let selector = SheetSelector::Name("fills".to_string());
+        assert_eq!(selector.resolve(&sheets).unwrap(), "Fills");
+    }
+
+    #[test]
+    fn sheet_selector_resolves_negative_index_from_the_end() {
+        let sheets = vec!["Fills".to_string(), "Summary".to_string()];
+        let selector = SheetSelector::Index(-1);
+        assert_eq!(selector.resolve(&sheets).unwrap(), "Summary");
+    }
+
+    #[test]
+    fn sheet_selector_errors_on_out_of_range_index() {
+        let sheets = vec!["Fills".to_string()];
+        let selector = SheetSelector::Index(5);
+        assert!(selector.resolve(&sheets).is_err());
+    }
+
+    #[test]
+    fn filter_short_circuits_when_no_filter_is_requested() {
+        let rows = vec![
+            vec!["Date".to_string(), "Instrument".to_string(), "Qty".to_string()],
+            vec!["1/1/2026".to_string(), "ESZ6".to_string(), "1".to_string()],
+        ];
+        let filtered = filter(rows.clone(), &[], None, None).unwrap();
+        assert_eq!(filtered, rows);
+    }
+}
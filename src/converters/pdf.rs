@@ -0,0 +1,245 @@
+//! PDF fills-report extraction, gated behind the `pdf` feature.
+//!
+//! Many brokers only hand out fills as a printed-style PDF rather than an
+//! XLSX export. This walks each page's content stream, tracking the current
+//! text position from `Tm`/`Td` operators and collecting the drawn string
+//! from each `Tj`/`TJ`, then reconstructs a grid by bucketing fragments into
+//! rows by y-coordinate and columns by x-coordinate. The result is the same
+//! `Vec<Vec<String>>` shape [`super::parse_xlsx`] produces, so
+//! [`super::cqg_fill_report::convert`]'s header-index logic runs unchanged.
+
+use std::path::Path;
+use std::sync::OnceLock;
+
+use lopdf::content::Content;
+use lopdf::{Document, Object};
+use regex::Regex;
+
+/// Tolerance (in PDF user-space units) for grouping text fragments into the
+/// same row or column.
+const POSITION_EPSILON: f64 = 2.0;
+
+/// A vertical gap larger than this is treated as a page/section break and
+/// recorded as a blank row, mirroring the blank line that separates data
+/// from the disclaimer footer in the XLSX fill reports.
+const SECTION_BREAK_GAP: f64 = POSITION_EPSILON * 6.0;
+
+/// A single piece of text drawn at a known position.
+struct Fragment {
+    x: f64,
+    y: f64,
+    text: String,
+}
+
+/// Parses a fills-report PDF into the same `Vec<Vec<String>>` shape
+/// `parse_xlsx` produces.
+pub fn parse_pdf(path: &Path) -> Result<Vec<Vec<String>>, Box<dyn std::error::Error>> {
+    let doc = Document::load(path)?;
+
+    let mut fragments = Vec::new();
+    for (_, page_id) in doc.get_pages() {
+        fragments.extend(extract_page_fragments(&doc, page_id)?);
+    }
+
+    Ok(layout_grid(fragments))
+}
+
+/// Replays a single page's content stream, tracking the text position and
+/// collecting every drawn string as a [`Fragment`].
+fn extract_page_fragments(
+    doc: &Document,
+    page_id: (u32, u16),
+) -> Result<Vec<Fragment>, Box<dyn std::error::Error>> {
+    let content_data = doc.get_page_content(page_id)?;
+    let content = Content::decode(&content_data)?;
+
+    let mut fragments = Vec::new();
+    let mut pos = (0.0_f64, 0.0_f64);
+
+    for op in content.operations {
+        match op.operator.as_str() {
+            "Tm" => {
+                if let (Some(x), Some(y)) = (as_f64(op.operands.get(4)), as_f64(op.operands.get(5))) {
+                    pos = (x, y);
+                }
+            }
+            "Td" | "TD" => {
+                if let (Some(dx), Some(dy)) = (as_f64(op.operands.first()), as_f64(op.operands.get(1))) {
+                    pos = (pos.0 + dx, pos.1 + dy);
+                }
+            }
+            "Tj" => {
+                if let Some(text) = op.operands.first().and_then(as_string) {
+                    push_fragment(&mut fragments, pos, text);
+                }
+            }
+            "TJ" => {
+                if let Some(Object::Array(items)) = op.operands.first() {
+                    let text: String = items.iter().filter_map(as_string).collect();
+                    push_fragment(&mut fragments, pos, text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(fragments)
+}
+
+fn push_fragment(fragments: &mut Vec<Fragment>, pos: (f64, f64), text: String) {
+    if text.trim().is_empty() {
+        return;
+    }
+    fragments.push(Fragment { x: pos.0, y: pos.1, text });
+}
+
+fn as_f64(obj: Option<&Object>) -> Option<f64> {
+    match obj {
+        Some(Object::Integer(i)) => Some(*i as f64),
+        Some(Object::Real(f)) => Some(*f as f64),
+        _ => None,
+    }
+}
+
+fn as_string(obj: &Object) -> Option<String> {
+    match obj {
+        Object::String(bytes, _) => Some(String::from_utf8_lossy(bytes).to_string()),
+        _ => None,
+    }
+}
+
+/// Buckets fragments into rows (sorted top-to-bottom) and, within each row,
+/// into columns (sorted left-to-right), merging fragments that land in the
+/// same cell and inserting a blank row across large vertical gaps.
+fn layout_grid(mut fragments: Vec<Fragment>) -> Vec<Vec<String>> {
+    if fragments.is_empty() {
+        return Vec::new();
+    }
+
+    // PDF y-coordinates increase upward, so "top to bottom" is descending y;
+    // within a row, left to right is ascending x. `total_cmp` tolerates a
+    // malformed/adversarial NaN coordinate from the content stream instead of
+    // panicking; such fragments just sort to one end rather than grouping
+    // meaningfully, which is an acceptable degradation for garbage input.
+    fragments.sort_by(|a, b| b.y.total_cmp(&a.y).then(a.x.total_cmp(&b.x)));
+
+    let mut rows: Vec<Vec<Fragment>> = Vec::new();
+    for fragment in fragments {
+        match rows.last_mut() {
+            Some(row) if (row[0].y - fragment.y).abs() <= POSITION_EPSILON => {
+                row.push(fragment);
+            }
+            Some(row) if (row[0].y - fragment.y).abs() > SECTION_BREAK_GAP => {
+                rows.push(Vec::new()); // blank row marks the section break
+                rows.push(vec![fragment]);
+            }
+            _ => rows.push(vec![fragment]),
+        }
+    }
+
+    rows.into_iter().map(merge_row_into_cells).collect()
+}
+
+/// Merges fragments within a row into cells by x-coordinate proximity.
+fn merge_row_into_cells(mut row: Vec<Fragment>) -> Vec<String> {
+    row.sort_by(|a, b| a.x.total_cmp(&b.x));
+
+    let mut cells: Vec<(f64, String)> = Vec::new();
+    for fragment in row {
+        match cells.last_mut() {
+            Some((x, text)) if (fragment.x - *x).abs() <= POSITION_EPSILON => {
+                text.push_str(&fragment.text);
+            }
+            _ => cells.push((fragment.x, fragment.text)),
+        }
+    }
+
+    cells.into_iter().map(|(_, text)| classify_cell(text)).collect()
+}
+
+/// Trims incidental whitespace and, for quantity-like cells, strips a
+/// thousands separator that a PDF renderer may have drawn as a fragment of
+/// its own (e.g. `"1,234"` split across glyph runs) so the quantity comes
+/// out as plain digits matching the `^\d+$` pattern below.
+fn classify_cell(text: String) -> String {
+    static DATE_RE: OnceLock<Regex> = OnceLock::new();
+    static QTY_RE: OnceLock<Regex> = OnceLock::new();
+
+    let date_re = DATE_RE.get_or_init(|| Regex::new(r"^\d{1,2}/\d{1,2}/\d{4}$").unwrap());
+    let qty_re = QTY_RE.get_or_init(|| Regex::new(r"^\d+$").unwrap());
+
+    let trimmed = text.trim();
+    if date_re.is_match(trimmed) {
+        return trimmed.to_string();
+    }
+
+    let without_commas: String = trimmed.chars().filter(|&c| c != ',').collect();
+    if qty_re.is_match(&without_commas) {
+        return without_commas;
+    }
+
+    trimmed.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fragment(x: f64, y: f64, text: &str) -> Fragment {
+        Fragment { x, y, text: text.to_string() }
+    }
+
+    #[test]
+    fn layout_grid_groups_fragments_into_rows_and_columns() {
+        let fragments = vec![
+            fragment(0.0, 100.0, "12/10/25"),
+            fragment(50.0, 100.0, "9:30:15"),
+            fragment(0.0, 90.0, "12/11/25"),
+            fragment(50.0, 90.0, "9:31:00"),
+        ];
+
+        let rows = layout_grid(fragments);
+
+        assert_eq!(rows, vec![
+            vec!["12/10/25".to_string(), "9:30:15".to_string()],
+            vec!["12/11/25".to_string(), "9:31:00".to_string()],
+        ]);
+    }
+
+    #[test]
+    fn layout_grid_inserts_blank_row_across_a_section_break() {
+        let fragments = vec![
+            fragment(0.0, 100.0, "12/10/25"),
+            fragment(0.0, 100.0 - SECTION_BREAK_GAP - 1.0, "Disclaimer"),
+        ];
+
+        let rows = layout_grid(fragments);
+
+        assert_eq!(rows.len(), 3);
+        assert!(rows[1].is_empty());
+    }
+
+    #[test]
+    fn layout_grid_does_not_panic_on_nan_coordinates() {
+        let fragments = vec![fragment(f64::NAN, f64::NAN, "garbage"), fragment(0.0, 0.0, "ok")];
+
+        // Must not panic; the exact ordering of NaN fragments is unspecified.
+        let rows = layout_grid(fragments);
+        assert_eq!(rows.iter().flatten().count(), 2);
+    }
+
+    #[test]
+    fn classify_cell_passes_through_dates_unchanged() {
+        assert_eq!(classify_cell("12/10/2025".to_string()), "12/10/2025");
+    }
+
+    #[test]
+    fn classify_cell_strips_thousands_separators_from_quantities() {
+        assert_eq!(classify_cell("1,234".to_string()), "1234");
+    }
+
+    #[test]
+    fn classify_cell_leaves_other_text_untouched() {
+        assert_eq!(classify_cell("  ESZ6  ".to_string()), "ESZ6");
+    }
+}
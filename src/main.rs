@@ -4,6 +4,8 @@ use std::path::PathBuf;
 
 use clap::{Parser, Subcommand, ValueEnum};
 
+use converters::{CellRange, Delimiter, SheetSelector};
+
 #[derive(Parser)]
 #[command(version, about = "Convert trading reports to TraderVue format", long_about = None)]
 struct Cli {
@@ -23,24 +25,87 @@ enum Commands {
         #[arg(short, long)]
         input: PathBuf,
 
-        /// Path to output CSV file (defaults to input file with .csv extension)
+        #[command(flatten)]
+        options: ConvertOptions,
+    },
+
+    /// Report worksheet names, dimensions, and detected header rows without converting
+    Metadata {
+        /// Path to input XLSX file
         #[arg(short, long)]
-        output: Option<PathBuf>,
+        input: PathBuf,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "json")]
+        format: MetaFormat,
     },
 }
 
+/// Conversion-time flags beyond the report type and input path, bundled so
+/// `handle_convert` doesn't accumulate one positional parameter per request.
+#[derive(clap::Args)]
+struct ConvertOptions {
+    /// Worksheet to read, by case-insensitive name or index (negative
+    /// counts from the end, e.g. -1 = last sheet). Defaults to the first
+    /// worksheet.
+    #[arg(long)]
+    sheet: Option<SheetSelector>,
+
+    /// Restrict extraction to an A1-style cell range (e.g. C3:T25),
+    /// clamped to the worksheet's used range. Defaults to the whole sheet.
+    #[arg(long)]
+    range: Option<CellRange>,
+
+    /// Only keep rows for this symbol (repeatable); defaults to all symbols
+    #[arg(long)]
+    symbol: Vec<String>,
+
+    /// Only keep rows at or after this time (HH:MM:SS or full timestamp)
+    #[arg(long)]
+    from: Option<String>,
+
+    /// Only keep rows at or before this time (HH:MM:SS or full timestamp)
+    #[arg(long)]
+    to: Option<String>,
+
+    /// Output field delimiter
+    #[arg(long, default_value = ",")]
+    delimiter: Delimiter,
+
+    /// Path to output CSV file, or "-" to stream to stdout (defaults to
+    /// input file with .csv extension)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
 #[derive(Clone, ValueEnum)]
 enum ReportType {
     /// CQG Fill Report
     CQGFillReport,
+    /// Detect the report format automatically from the input rows
+    Auto,
+}
+
+#[derive(Clone, ValueEnum)]
+enum MetaFormat {
+    /// Pretty-printed JSON
+    Json,
+    /// CSV table
+    Csv,
 }
 
 fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Convert { r#type, input, output } => {
-            if let Err(e) = handle_convert(r#type, input, output) {
+        Commands::Convert { r#type, input, options } => {
+            if let Err(e) = handle_convert(r#type, input, options) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Metadata { input, format } => {
+            if let Err(e) = handle_metadata(input, format) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
@@ -51,23 +116,68 @@ fn main() {
 fn handle_convert(
     report_type: ReportType,
     input: PathBuf,
-    output: Option<PathBuf>,
+    options: ConvertOptions,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    // Generate output path if not provided
-    let output = output.unwrap_or_else(|| {
-        input.with_extension("csv")
-    });
-    // Parse XLSX to Vec<Vec<String>>
-    let rows = converters::parse_xlsx(&input)?;
+    // "-" streams to stdout instead of naming an output file
+    let to_stdout = options.output.as_deref() == Some(std::path::Path::new("-"));
+
+    // Parse the input into Vec<Vec<String>>; PDF fills reports take a
+    // separate, feature-gated extraction path alongside the XLSX one.
+    let is_pdf = input
+        .extension()
+        .map(|ext| ext.eq_ignore_ascii_case("pdf"))
+        .unwrap_or(false);
+
+    let rows = if is_pdf {
+        #[cfg(feature = "pdf")]
+        {
+            converters::pdf::parse_pdf(&input)?
+        }
+        #[cfg(not(feature = "pdf"))]
+        {
+            return Err("PDF input requires building with the 'pdf' feature enabled".into());
+        }
+    } else {
+        converters::parse_xlsx(&input, options.sheet.as_ref(), options.range.as_ref())?
+    };
 
     // Convert based on type
     let csv_rows = match report_type {
         ReportType::CQGFillReport => converters::cqg_fill_report::convert(rows)?,
+        ReportType::Auto => converters::convert_auto(rows)?,
     };
 
-    // Write to CSV
-    converters::write_csv(&output, csv_rows)?;
+    // Narrow down to the requested time window and/or symbols
+    let csv_rows = converters::filter(
+        csv_rows,
+        &options.symbol,
+        options.from.as_deref(),
+        options.to.as_deref(),
+    )?;
+
+    // Write to CSV, either to stdout or to a file (defaulting to the input
+    // file with a .csv extension)
+    if to_stdout {
+        let stdout = std::io::stdout();
+        converters::write_csv(stdout.lock(), csv_rows, options.delimiter)?;
+    } else {
+        let output = options.output.unwrap_or_else(|| input.with_extension("csv"));
+        let file = std::fs::File::create(&output)?;
+        converters::write_csv(file, csv_rows, options.delimiter)?;
+        println!("Successfully converted {} to {}", input.display(), output.display());
+    }
+
+    Ok(())
+}
+
+fn handle_metadata(input: PathBuf, format: MetaFormat) -> Result<(), Box<dyn std::error::Error>> {
+    let sheets = converters::metadata::inspect(&input)?;
+
+    let rendered = match format {
+        MetaFormat::Json => converters::metadata::to_json(&sheets)?,
+        MetaFormat::Csv => converters::metadata::to_csv(&sheets)?,
+    };
 
-    println!("Successfully converted {} to {}", input.display(), output.display());
+    print!("{}", rendered);
     Ok(())
 }
\ No newline at end of file